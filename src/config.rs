@@ -0,0 +1,146 @@
+//! Runtime configuration for in-game constants, loaded from a TOML file on
+//! disk so the board size, speed, and palette can be retuned without
+//! recompiling.
+
+use std::fs;
+use std::path::Path;
+
+use raylib::prelude::{Color, Vector2};
+use serde::Deserialize;
+
+/// Path to the configuration file loaded at startup, relative to the
+/// working directory.
+const CONFIG_PATH: &str = "config.toml";
+
+/// Selects what happens when the snake's head reaches the edge of the
+/// board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WrapMode {
+    /// The snake reappears on the opposite edge of the board.
+    Wrap,
+    /// Touching the edge of the board ends the game.
+    Walls,
+}
+
+/// Tunable constants for a game session. Any field missing from
+/// [`CONFIG_PATH`] falls back to the value in [`Config::default`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub screen_width: i32,
+    pub screen_height: i32,
+    pub grid_scale: i32,
+    pub ticks_per_second: u128,
+    pub initial_length: u8,
+    pub wrap_mode: WrapMode,
+    pub obstacle_count: u32,
+    pub autopilot: bool,
+    pub food_color: [u8; 3],
+    pub snake_head_color: [u8; 3],
+    pub snake_tail_color: [u8; 3],
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            screen_width: 720,
+            screen_height: 480,
+            grid_scale: 30,
+            ticks_per_second: 10,
+            initial_length: 1,
+            wrap_mode: WrapMode::Wrap,
+            obstacle_count: 0,
+            autopilot: false,
+            food_color: [230, 41, 55],
+            snake_head_color: [0, 228, 48],
+            snake_tail_color: [0, 117, 44],
+        }
+    }
+}
+
+impl Config {
+    /// Loads configuration from [`CONFIG_PATH`], falling back to
+    /// [`Config::default`] when the file doesn't exist. Returns a clean
+    /// error message instead of panicking if the file can't be parsed or
+    /// the loaded values fail validation.
+    pub fn load() -> Result<Self, String> {
+        let config: Config = if Path::new(CONFIG_PATH).exists() {
+            let contents = fs::read_to_string(CONFIG_PATH)
+                .map_err(|e| format!("could not read {CONFIG_PATH}: {e}"))?;
+            toml::from_str(&contents)
+                .map_err(|e| format!("could not parse {CONFIG_PATH}: {e}"))?
+        } else {
+            Config::default()
+        };
+
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.grid_scale <= 0 {
+            return Err("grid_scale must be positive".to_string());
+        }
+        if self.screen_width % self.grid_scale != 0 {
+            return Err(
+                "screen_width must be divisible by grid_scale".to_string()
+            );
+        }
+        if self.screen_height % self.grid_scale != 0 {
+            return Err(
+                "screen_height must be divisible by grid_scale".to_string()
+            );
+        }
+        if self.ticks_per_second == 0 {
+            return Err("ticks_per_second must be positive".to_string());
+        }
+        if self.initial_length == 0 {
+            return Err("initial_length must be positive".to_string());
+        }
+
+        let free_cells = (self.cols() * self.rows()) as u32;
+        if self.obstacle_count + self.initial_length as u32 + 1 > free_cells {
+            return Err(
+                "obstacle_count leaves no free cells for the snake and food"
+                    .to_string(),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Number of vertical columns in the on-screen grid.
+    pub fn cols(&self) -> i32 {
+        self.screen_width / self.grid_scale
+    }
+
+    /// Number of horizontal rows in the on-screen grid.
+    pub fn rows(&self) -> i32 {
+        self.screen_height / self.grid_scale
+    }
+
+    /// [Vector2] representation of [`Config::grid_scale`].
+    pub fn grid_square(&self) -> Vector2 {
+        Vector2 {
+            x: self.grid_scale as f32,
+            y: self.grid_scale as f32,
+        }
+    }
+
+    pub fn food_color(&self) -> Color {
+        let [r, g, b] = self.food_color;
+        Color::new(r, g, b, 255)
+    }
+
+    pub fn snake_head_color(&self) -> Color {
+        let [r, g, b] = self.snake_head_color;
+        Color::new(r, g, b, 255)
+    }
+
+    pub fn snake_tail_color(&self) -> Color {
+        let [r, g, b] = self.snake_tail_color;
+        Color::new(r, g, b, 255)
+    }
+}
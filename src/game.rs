@@ -1,114 +1,242 @@
 //! Definitions for the [Game] and [GameState] structs.
 
+use std::time::Instant;
+
 use rand::prelude::*;
 use raylib::prelude::*;
 
 use snake::Snake;
 use tick::TickCounter;
 
+mod autopilot;
 mod snake;
 mod tick;
 
-use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use crate::config::Config;
 
-/// Number of times the game renders each second.
-const TICKS_PER_SECOND: u128 = 10;
+/// Points awarded for eating a piece of food, before any time bonus.
+const BASE_FOOD_POINTS: u64 = 10;
 
-/// Pixel size of a grid square.
-const GRID_SCALE: i32 = 30;
+/// Number of countdown units a spawned food survives before it expires.
+const FOOD_TIMER_UNITS: u32 = 10;
 
-/// [Vector2] representation of [`GRID_SCALE`].
-const GRID_SQUARE: Vector2 = Vector2 {
-    x: GRID_SCALE as f32,
-    y: GRID_SCALE as f32,
-};
+/// Real-time duration of a single countdown unit.
+const FOOD_TIMER_UNIT_MILLIS: u128 = 800;
 
-/// Number of vertical columns in the on-screen grid.
-const COLS: i32 = SCREEN_WIDTH / GRID_SCALE;
+/// Score awarded per countdown unit still remaining when food is eaten.
+const TIME_BONUS_PER_UNIT: u64 = 10;
 
-/// Number of horizontal rows in the on-screen grid.
-const ROWS: i32 = SCREEN_HEIGHT / GRID_SCALE;
+const FOOD_TIMER_SIZE: i32 = 20;
+const FOOD_TIMER_COLOR: Color = Color::RED;
 
 const BACKGROUND_COLOR: Color = Color::WHITE;
 const GRID_LINE_COLOR: Color = Color::WHITE;
 
-/// Color of the snake food shown on-screen.
-const FOOD_COLOR: Color = Color::RED;
-
 const LIVE_SCORE_SIZE: i32 = 20;
 const LIVE_SCORE_COLOR: Color = Color::GREEN;
 
+/// Color of interior obstacle blocks.
+const OBSTACLE_COLOR: Color = Color::DARKGRAY;
+
+/// Score needed to raise the tick rate by one level.
+const SCORE_PER_LEVEL: u64 = 50;
+
+/// Highest tick rate the difficulty curve will reach, regardless of score.
+const MAX_TICKS_PER_SECOND: u128 = 30;
+
 /// Represents the state of the current game session.
 pub enum GameState {
     RUNNING,
+    PAUSED,
     ENDED,
 }
 
 /// Tracks all game objects.
 pub struct Game {
     pub state: GameState,
+    config: Config,
     tick_counter: TickCounter,
     rng: ThreadRng,
     score: u64,
     snake: Snake,
     food: Vector2,
+    obstacles: Vec<Vector2>,
+    food_spawned_at: Instant,
+    paused_at: Instant,
+    food_timer: u32,
+    base_ticks_per_second: u128,
+    level: u32,
+    grid_scale: i32,
+    grid_square: Vector2,
+    cols: i32,
+    rows: i32,
+    screen_width: i32,
+    screen_height: i32,
+    food_color: Color,
+    autopilot: bool,
 }
 
 impl Game {
-    pub fn init() -> Self {
-        assert!(GRID_SCALE > 0, "grid scale is negative");
-        assert!(
-            SCREEN_WIDTH % GRID_SCALE == 0,
-            "screen width must be divisible by grid scale"
-        );
-        assert!(
-            SCREEN_HEIGHT % GRID_SCALE == 0,
-            "screen height must be divisible by grid scale"
-        );
-
+    pub fn init(config: &Config) -> Self {
         let mut g = Game {
             state: GameState::RUNNING,
-            tick_counter: TickCounter::start(TICKS_PER_SECOND),
+            config: config.clone(),
+            tick_counter: TickCounter::start(config.ticks_per_second),
             rng: rand::thread_rng(),
             score: 0,
-            snake: Snake::new(Color::GREEN, Color::DARKGREEN),
+            snake: Snake::new(config),
             food: Vector2 { x: 0.0, y: 0.0 },
+            obstacles: Vec::new(),
+            food_spawned_at: Instant::now(),
+            paused_at: Instant::now(),
+            food_timer: FOOD_TIMER_UNITS,
+            base_ticks_per_second: config.ticks_per_second,
+            level: 0,
+            grid_scale: config.grid_scale,
+            grid_square: config.grid_square(),
+            cols: config.cols(),
+            rows: config.rows(),
+            screen_width: config.screen_width,
+            screen_height: config.screen_height,
+            food_color: config.food_color(),
+            autopilot: config.autopilot,
         };
 
+        g.obstacles = g.generate_obstacles(config.obstacle_count);
         g.move_food();
 
         g
     }
 
+    /// Re-initializes the snake, score, tick counter, food, and obstacles
+    /// for a new session, reusing the already-loaded [`Config`] and the
+    /// open raylib window.
+    pub fn reset(&mut self) {
+        let ticks_per_second = self.config.ticks_per_second;
+        let obstacle_count = self.config.obstacle_count;
+
+        self.state = GameState::RUNNING;
+        self.tick_counter = TickCounter::start(ticks_per_second);
+        self.score = 0;
+        self.snake = Snake::new(&self.config);
+        self.food_timer = FOOD_TIMER_UNITS;
+        self.base_ticks_per_second = ticks_per_second;
+        self.level = 0;
+
+        self.obstacles = self.generate_obstacles(obstacle_count);
+        self.move_food();
+    }
+
+    /// Resumes a [`GameState::PAUSED`] session. Shifts `food_spawned_at`
+    /// forward by the duration spent paused so that time isn't counted
+    /// against the food timer, without refilling it outright.
+    pub fn resume(&mut self) {
+        self.state = GameState::RUNNING;
+        self.food_spawned_at += self.paused_at.elapsed();
+    }
+
+    fn generate_obstacles(&mut self, count: u32) -> Vec<Vector2> {
+        let mut obstacles = Vec::new();
+
+        for _ in 0..count {
+            let mut gen_pos = || Vector2 {
+                x: (self.rng.gen_range(0..self.cols) * self.grid_scale) as f32,
+                y: (self.rng.gen_range(0..self.rows) * self.grid_scale) as f32,
+            };
+
+            let mut new_pos = gen_pos();
+            while self.snake.body().contains(&new_pos)
+                || obstacles.contains(&new_pos)
+            {
+                new_pos = gen_pos();
+            }
+
+            obstacles.push(new_pos);
+        }
+
+        obstacles
+    }
+
     fn move_food(&mut self) {
         let mut gen_pos = || Vector2 {
-            x: (self.rng.gen_range(0..COLS) * GRID_SCALE) as f32,
-            y: (self.rng.gen_range(0..ROWS) * GRID_SCALE) as f32,
+            x: (self.rng.gen_range(0..self.cols) * self.grid_scale) as f32,
+            y: (self.rng.gen_range(0..self.rows) * self.grid_scale) as f32,
         };
 
         let mut new_pos = gen_pos();
-        while self.snake.body().contains(&new_pos) {
+        while self.snake.body().contains(&new_pos)
+            || self.obstacles.contains(&new_pos)
+        {
             new_pos = gen_pos();
         }
 
         self.food = new_pos;
+        self.food_spawned_at = Instant::now();
+        self.food_timer = FOOD_TIMER_UNITS;
     }
 
     pub fn update(&mut self, rl: &mut RaylibHandle) {
         let keyboard_input = rl.get_key_pressed();
-        self.snake.handle_input(keyboard_input);
+
+        if matches!(
+            keyboard_input,
+            Some(KeyboardKey::KEY_P) | Some(KeyboardKey::KEY_SPACE)
+        ) {
+            self.state = GameState::PAUSED;
+            self.paused_at = Instant::now();
+            return;
+        }
+
+        if !self.autopilot {
+            self.snake.handle_input(keyboard_input);
+        }
+
+        let elapsed_millis =
+            (self.tick_counter.seconds_since(self.food_spawned_at) * 1000.0) as u128;
+        let units_elapsed = (elapsed_millis / FOOD_TIMER_UNIT_MILLIS) as u32;
+        self.food_timer = FOOD_TIMER_UNITS.saturating_sub(units_elapsed);
+
+        if self.food_timer == 0 {
+            self.state = GameState::ENDED;
+            return;
+        }
 
         if self.tick_counter.is_next_tick() {
-            self.snake.update();
+            if self.autopilot {
+                let direction = autopilot::solve(
+                    &self.snake,
+                    self.food,
+                    &self.obstacles,
+                    self.cols,
+                    self.rows,
+                    self.grid_scale,
+                );
+                self.snake.steer(direction);
+            }
+
+            if self.snake.update() {
+                self.state = GameState::ENDED;
+                return;
+            }
 
             if self.snake.tail_iter().any(|v| *v == self.snake.head()) {
                 self.state = GameState::ENDED;
             }
 
+            if self.obstacles.contains(&self.snake.head()) {
+                self.state = GameState::ENDED;
+            }
+
             if self.snake.head() == self.food {
                 self.snake.add_tail_block();
+                self.score += BASE_FOOD_POINTS
+                    + self.food_timer as u64 * TIME_BONUS_PER_UNIT;
                 self.move_food();
-                self.score += 10;
+
+                self.level = (self.score / SCORE_PER_LEVEL) as u32;
+                let tps = (self.base_ticks_per_second + self.level as u128)
+                    .min(MAX_TICKS_PER_SECOND);
+                self.tick_counter.set_ticks_per_second(tps);
             }
         }
     }
@@ -117,19 +245,23 @@ impl Game {
         d.clear_background(BACKGROUND_COLOR);
 
         // Draw grid
-        for i in 1..COLS {
-            let col_x = i * GRID_SCALE;
-            d.draw_line(col_x, 0, col_x, SCREEN_HEIGHT, GRID_LINE_COLOR);
+        for i in 1..self.cols {
+            let col_x = i * self.grid_scale;
+            d.draw_line(col_x, 0, col_x, self.screen_height, GRID_LINE_COLOR);
         }
 
-        for i in 1..ROWS {
-            let row_y = i * GRID_SCALE;
-            d.draw_line(0, row_y, SCREEN_WIDTH, row_y, GRID_LINE_COLOR);
+        for i in 1..self.rows {
+            let row_y = i * self.grid_scale;
+            d.draw_line(0, row_y, self.screen_width, row_y, GRID_LINE_COLOR);
         }
 
         self.snake.draw(d);
 
-        d.draw_rectangle_v(self.food, GRID_SQUARE, FOOD_COLOR);
+        for obstacle in &self.obstacles {
+            d.draw_rectangle_v(*obstacle, self.grid_square, OBSTACLE_COLOR);
+        }
+
+        d.draw_rectangle_v(self.food, self.grid_square, self.food_color);
 
         d.draw_text(
             &format!("SCORE: {}", self.score),
@@ -138,6 +270,14 @@ impl Game {
             LIVE_SCORE_SIZE,
             LIVE_SCORE_COLOR,
         );
+
+        d.draw_text(
+            &format!("TIME: {}", self.food_timer),
+            0,
+            LIVE_SCORE_SIZE,
+            FOOD_TIMER_SIZE,
+            FOOD_TIMER_COLOR,
+        );
     }
 
     pub fn score(&self) -> u64 {
@@ -0,0 +1,153 @@
+//! BFS-based solver that steers the snake toward the food, for the
+//! self-playing demo mode.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use raylib::prelude::Vector2;
+
+use super::snake::{Direction, Snake};
+
+/// A grid cell, addressed by (column, row).
+type Cell = (i32, i32);
+
+const DIRECTIONS: [Direction; 4] =
+    [Direction::UP, Direction::DOWN, Direction::LEFT, Direction::RIGHT];
+
+fn to_cell(pos: Vector2, grid_scale: i32) -> Cell {
+    (pos.x as i32 / grid_scale, pos.y as i32 / grid_scale)
+}
+
+fn neighbor(cell: Cell, dir: Direction, cols: i32, rows: i32) -> Option<Cell> {
+    let delta = dir.v();
+    let next = (cell.0 + delta.x as i32, cell.1 + delta.y as i32);
+
+    if next.0 < 0 || next.0 >= cols || next.1 < 0 || next.1 >= rows {
+        None
+    } else {
+        Some(next)
+    }
+}
+
+/// Computes the next [Direction] the snake should steer in to reach
+/// `food`. Finds a shortest path via BFS over the `cols` x `rows` grid,
+/// treating the snake's body (excluding the tail cell it's about to
+/// vacate) and `obstacles` as blocked. If no path exists, falls back to
+/// the move that maximizes reachable free space, to avoid trapping the
+/// snake against itself.
+pub fn solve(
+    snake: &Snake,
+    food: Vector2,
+    obstacles: &[Vector2],
+    cols: i32,
+    rows: i32,
+    grid_scale: i32,
+) -> Direction {
+    let body: Vec<Cell> =
+        snake.body().iter().map(|v| to_cell(*v, grid_scale)).collect();
+    let start = body[0];
+    let goal = to_cell(food, grid_scale);
+
+    let blocked: HashSet<Cell> = body[..body.len().saturating_sub(1)]
+        .iter()
+        .copied()
+        .chain(obstacles.iter().map(|v| to_cell(*v, grid_scale)))
+        .collect();
+
+    bfs_first_step(start, goal, &blocked, cols, rows)
+        .unwrap_or_else(|| widest_escape(start, &blocked, cols, rows, snake.direction()))
+}
+
+/// Returns the first step of a shortest path from `start` to `goal`, or
+/// `None` if `goal` is unreachable.
+fn bfs_first_step(
+    start: Cell,
+    goal: Cell,
+    blocked: &HashSet<Cell>,
+    cols: i32,
+    rows: i32,
+) -> Option<Direction> {
+    let mut visited = HashSet::new();
+    let mut predecessor: HashMap<Cell, (Cell, Direction)> = HashMap::new();
+    let mut frontier = VecDeque::new();
+
+    visited.insert(start);
+    frontier.push_back(start);
+
+    while let Some(cell) = frontier.pop_front() {
+        if cell == goal {
+            let mut step = None;
+            let mut cur = goal;
+            while cur != start {
+                let &(prev, dir) = predecessor.get(&cur)?;
+                step = Some(dir);
+                cur = prev;
+            }
+            return step;
+        }
+
+        for &dir in &DIRECTIONS {
+            if let Some(next) = neighbor(cell, dir, cols, rows) {
+                if !visited.contains(&next) && !blocked.contains(&next) {
+                    visited.insert(next);
+                    predecessor.insert(next, (cell, dir));
+                    frontier.push_back(next);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Picks the legal move from `start` that leads to the largest
+/// flood-filled region of free space, falling back to `current` if every
+/// neighboring cell is blocked.
+fn widest_escape(
+    start: Cell,
+    blocked: &HashSet<Cell>,
+    cols: i32,
+    rows: i32,
+    current: Direction,
+) -> Direction {
+    DIRECTIONS
+        .into_iter()
+        .filter_map(|dir| {
+            let next = neighbor(start, dir, cols, rows)?;
+            if blocked.contains(&next) {
+                None
+            } else {
+                Some((dir, flood_fill_size(next, blocked, cols, rows)))
+            }
+        })
+        .max_by_key(|&(_, size)| size)
+        .map(|(dir, _)| dir)
+        .unwrap_or(current)
+}
+
+/// Counts the number of cells reachable from `start` without crossing
+/// `blocked`.
+fn flood_fill_size(
+    start: Cell,
+    blocked: &HashSet<Cell>,
+    cols: i32,
+    rows: i32,
+) -> usize {
+    let mut visited = HashSet::new();
+    let mut frontier = VecDeque::new();
+
+    visited.insert(start);
+    frontier.push_back(start);
+
+    while let Some(cell) = frontier.pop_front() {
+        for &dir in &DIRECTIONS {
+            if let Some(next) = neighbor(cell, dir, cols, rows) {
+                if !visited.contains(&next) && !blocked.contains(&next) {
+                    visited.insert(next);
+                    frontier.push_back(next);
+                }
+            }
+        }
+    }
+
+    visited.len()
+}
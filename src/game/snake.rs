@@ -4,22 +4,17 @@ use std::collections::VecDeque;
 
 use raylib::prelude::*;
 
-use super::{COLS, GRID_SCALE, GRID_SQUARE, ROWS};
-use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
-const S_HEIGHT_F32: f32 = SCREEN_HEIGHT as f32;
-const S_WIDTH_F32: f32 = SCREEN_WIDTH as f32;
-
-const INITIAL_LENGTH: u8 = 1;
+use crate::config::{Config, WrapMode};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
-enum Direction {
+pub(crate) enum Direction {
     UP,
     DOWN,
     LEFT,
     RIGHT,
 }
 impl Direction {
-    const fn v(&self) -> Vector2 {
+    pub(crate) const fn v(&self) -> Vector2 {
         match self {
             &Self::UP => Vector2 { x: 0.0, y: -1.0 },
             &Self::DOWN => Vector2 { x: 0.0, y: 1.0 },
@@ -27,6 +22,15 @@ impl Direction {
             &Self::RIGHT => Vector2 { x: 1.0, y: 0.0 },
         }
     }
+
+    const fn reverse(&self) -> Self {
+        match self {
+            &Self::UP => Self::DOWN,
+            &Self::DOWN => Self::UP,
+            &Self::LEFT => Self::RIGHT,
+            &Self::RIGHT => Self::LEFT,
+        }
+    }
 }
 
 /// Represents the position and movement direction of the on-screen snake.
@@ -39,21 +43,30 @@ pub struct Snake {
     segments: VecDeque<Vector2>,
     head_color: Color,
     tail_color: Color,
+    grid_scale: i32,
+    grid_square: Vector2,
+    screen_width: i32,
+    screen_height: i32,
+    wrap_mode: WrapMode,
 }
 
 impl Snake {
-    pub fn new(head_color: Color, tail_color: Color) -> Self {
+    pub fn new(config: &Config) -> Self {
         let direction = Direction::RIGHT;
+        let grid_scale = config.grid_scale;
+        let cols = config.cols();
+        let rows = config.rows();
+
         let mut segments: VecDeque<_> = vec![Vector2 {
-            x: ((COLS / 2) * GRID_SCALE) as f32,
-            y: ((ROWS / 2) * GRID_SCALE) as f32,
+            x: ((cols / 2) * grid_scale) as f32,
+            y: ((rows / 2) * grid_scale) as f32,
         }]
         .into();
 
         // Create extra squares behind head, to taste
-        for _ in 0..(INITIAL_LENGTH - 1) {
+        for _ in 0..(config.initial_length - 1) {
             segments.push_back(
-                *segments.back().unwrap() + direction.v() * -GRID_SCALE as f32,
+                *segments.back().unwrap() + direction.v() * -grid_scale as f32,
             );
         }
 
@@ -61,8 +74,13 @@ impl Snake {
             direction,
             prev_direction: direction,
             segments,
-            head_color,
-            tail_color,
+            head_color: config.snake_head_color(),
+            tail_color: config.snake_tail_color(),
+            grid_scale,
+            grid_square: config.grid_square(),
+            screen_width: config.screen_width,
+            screen_height: config.screen_height,
+            wrap_mode: config.wrap_mode,
         }
     }
 
@@ -79,38 +97,31 @@ impl Snake {
     }
 
     pub fn handle_input(&mut self, input: Option<KeyboardKey>) {
-        if let None = input {
-            return;
-        }
+        let direction = match input {
+            Some(KeyboardKey::KEY_W) => Direction::UP,
+            Some(KeyboardKey::KEY_A) => Direction::LEFT,
+            Some(KeyboardKey::KEY_S) => Direction::DOWN,
+            Some(KeyboardKey::KEY_D) => Direction::RIGHT,
+            _ => return,
+        };
+
+        self.steer(direction);
+    }
 
-        // Change direction unless that means turning directly backwards, unless
-        // the snake's length is 1
+    /// Sets the snake's direction unless that would mean turning directly
+    /// backwards, unless the snake's length is 1.
+    pub(crate) fn steer(&mut self, direction: Direction) {
         let one_block = self.segments.len() == 1;
-        match input {
-            Some(KeyboardKey::KEY_W) => {
-                if one_block || self.prev_direction != Direction::DOWN {
-                    self.direction = Direction::UP;
-                }
-            }
-            Some(KeyboardKey::KEY_A) => {
-                if one_block || self.prev_direction != Direction::RIGHT {
-                    self.direction = Direction::LEFT;
-                }
-            }
-            Some(KeyboardKey::KEY_S) => {
-                if one_block || self.prev_direction != Direction::UP {
-                    self.direction = Direction::DOWN;
-                }
-            }
-            Some(KeyboardKey::KEY_D) => {
-                if one_block || self.prev_direction != Direction::LEFT {
-                    self.direction = Direction::RIGHT;
-                }
-            }
-            _ => (),
+        if one_block || self.prev_direction != direction.reverse() {
+            self.direction = direction;
         }
     }
 
+    /// The direction the snake is currently moving in.
+    pub(crate) fn direction(&self) -> Direction {
+        self.direction
+    }
+
     pub fn add_tail_block(&mut self) {
         match self.segments.back() {
             Some(pos) => self.segments.push_back(*pos),
@@ -118,30 +129,49 @@ impl Snake {
         }
     }
 
-    pub fn update(&mut self) {
-        let mut next = self.head() + self.direction.v() * GRID_SCALE as f32;
-
-        // Wraparound
-        let x_bounds = (0.0, S_WIDTH_F32 - GRID_SCALE as f32);
-        if next.x < x_bounds.0 {
-            next.x = x_bounds.1;
-        }
-        if next.x > x_bounds.1 {
-            next.x = x_bounds.0;
-        }
-
-        let y_bounds = (0.0, S_HEIGHT_F32 - GRID_SCALE as f32);
-        if next.y < y_bounds.0 {
-            next.y = y_bounds.1;
-        }
-        if next.y > y_bounds.1 {
-            next.y = y_bounds.0;
+    /// Advances the snake by one grid square in its current direction.
+    ///
+    /// Returns `true` if the move was rejected because the head left the
+    /// board while in [`WrapMode::Walls`]; the snake is left unmoved in
+    /// that case so the caller can end the game.
+    pub fn update(&mut self) -> bool {
+        let mut next = self.head() + self.direction.v() * self.grid_scale as f32;
+
+        let x_bounds = (0.0, self.screen_width as f32 - self.grid_scale as f32);
+        let y_bounds = (0.0, self.screen_height as f32 - self.grid_scale as f32);
+        let out_of_bounds = next.x < x_bounds.0
+            || next.x > x_bounds.1
+            || next.y < y_bounds.0
+            || next.y > y_bounds.1;
+
+        match self.wrap_mode {
+            WrapMode::Walls => {
+                if out_of_bounds {
+                    return true;
+                }
+            }
+            WrapMode::Wrap => {
+                if next.x < x_bounds.0 {
+                    next.x = x_bounds.1;
+                }
+                if next.x > x_bounds.1 {
+                    next.x = x_bounds.0;
+                }
+                if next.y < y_bounds.0 {
+                    next.y = y_bounds.1;
+                }
+                if next.y > y_bounds.1 {
+                    next.y = y_bounds.0;
+                }
+            }
         }
 
         self.segments.push_front(next);
         self.segments.pop_back();
 
         self.prev_direction = self.direction;
+
+        false
     }
 
     pub fn draw(&self, d: &mut RaylibDrawHandle) {
@@ -151,7 +181,7 @@ impl Snake {
             } else {
                 self.tail_color
             };
-            d.draw_rectangle_v(self.segments[i], GRID_SQUARE, color)
+            d.draw_rectangle_v(self.segments[i], self.grid_square, color)
         }
     }
 }
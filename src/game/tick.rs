@@ -29,4 +29,18 @@ impl TickCounter {
 
         false
     }
+
+    /// Returns the number of seconds elapsed since `mark`.
+    pub fn seconds_since(&self, mark: Instant) -> f64 {
+        mark.elapsed().as_secs_f64()
+    }
+
+    /// Changes the tick cadence, re-anchoring the counter at the current
+    /// instant and restarting the tick count at zero so the rate change
+    /// doesn't fire a burst of catch-up ticks.
+    pub fn set_ticks_per_second(&mut self, ticks_per_second: u128) {
+        self.nanos_per_tick = 1_000_000_000 / ticks_per_second;
+        self.start = Instant::now();
+        self.tick = 0;
+    }
 }
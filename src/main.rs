@@ -6,23 +6,18 @@
 //! **Author:** Jude Muriithi (GitHub: [jkmuriithi](https://github.com/jkmuriithi))
 //!
 //! **TODO:**
-//! - Configuration file system for in-game constants
 //! - Linux/MacOS build tests
 
 // Hide debug console in Windows build
 #![cfg_attr(target_os = "windows",windows_subsystem = "windows")]
 
+use config::Config;
 use game::{Game, GameState};
 use raylib::prelude::*;
 
+mod config;
 mod game;
 
-/// Width of the game window in pixels.
-const SCREEN_WIDTH: i32 = 720;
-
-/// Height of the game window in pixels.
-const SCREEN_HEIGHT: i32 = 480;
-
 /// Horizontal pixel offset of the final score.
 const SCORE_OFFSET_X: i32 = 140;
 
@@ -34,13 +29,18 @@ const SCORE_OFFSET_Y: i32 = 190;
 
 /// Starts a new game session.
 fn main() {
+    let config = Config::load().unwrap_or_else(|e| {
+        eprintln!("snake: invalid configuration: {e}");
+        std::process::exit(1);
+    });
+
     let (mut rl, thread) = raylib::init()
         .title("Snake")
-        .size(SCREEN_WIDTH, SCREEN_HEIGHT)
+        .size(config.screen_width, config.screen_height)
         .vsync()
         .build();
 
-    let mut game = Game::init();
+    let mut game = Game::init(&config);
 
     while !rl.window_should_close() {
         match game.state {
@@ -49,7 +49,25 @@ fn main() {
                 let mut d = rl.begin_drawing(&thread);
                 game.draw(&mut d);
             }
+            GameState::PAUSED => {
+                if matches!(
+                    rl.get_key_pressed(),
+                    Some(KeyboardKey::KEY_P) | Some(KeyboardKey::KEY_SPACE)
+                ) {
+                    game.resume();
+                    continue;
+                }
+
+                let mut d = rl.begin_drawing(&thread);
+                game.draw(&mut d);
+                d.draw_text("PAUSED", SCORE_OFFSET_X, SCORE_OFFSET_Y, 40, Color::BLACK);
+            }
             GameState::ENDED => {
+                if matches!(rl.get_key_pressed(), Some(KeyboardKey::KEY_R)) {
+                    game.reset();
+                    continue;
+                }
+
                 let mut d = rl.begin_drawing(&thread);
                 let score = game.score();
 
@@ -68,6 +86,13 @@ fn main() {
                     100,
                     Color::BLACK,
                 );
+                d.draw_text(
+                    "Press R to restart",
+                    SCORE_OFFSET_X - digit_offset,
+                    SCORE_OFFSET_Y + 100,
+                    20,
+                    Color::BLACK,
+                );
             }
         }
     }